@@ -0,0 +1,64 @@
+use alloy_sol_types::SolValue;
+use argon2::{Algorithm, Argon2, Params, Version};
+use base64::{engine::general_purpose::STANDARD_NO_PAD, Engine as _};
+use risc0_zkvm::guest::env;
+
+fn main() {
+    // Private: the candidate password.
+    let password: Vec<u8> = env::read();
+
+    // Public: the PHC-format hash string, e.g.
+    // "$argon2id$v=19$m=65536,t=3,p=4$<b64salt>$<b64hash>".
+    let phc: String = env::read();
+
+    let fields: Vec<&str> = phc.split('$').collect();
+    assert_eq!(fields.len(), 6, "malformed PHC string");
+    assert_eq!(fields[1], "argon2id", "unsupported algorithm");
+
+    let version: u32 = fields[2]
+        .strip_prefix("v=")
+        .expect("missing version field")
+        .parse()
+        .expect("invalid version field");
+    assert_eq!(version, 19, "unsupported argon2 version");
+
+    let mut m_cost = 0u32;
+    let mut t_cost = 0u32;
+    let mut p_cost = 0u32;
+    for kv in fields[3].split(',') {
+        let (key, value) = kv.split_once('=').expect("malformed param field");
+        let value: u32 = value.parse().expect("invalid param value");
+        match key {
+            "m" => m_cost = value,
+            "t" => t_cost = value,
+            "p" => p_cost = value,
+            _ => panic!("unknown argon2 param {key}"),
+        }
+    }
+
+    let salt = STANDARD_NO_PAD.decode(fields[4]).expect("invalid salt encoding");
+    let expected_tag = STANDARD_NO_PAD.decode(fields[5]).expect("invalid hash encoding");
+
+    let params = Params::new(m_cost, t_cost, p_cost, Some(expected_tag.len()))
+        .expect("invalid argon2 params");
+    let argon2 = Argon2::new(Algorithm::Argon2id, Version::V0x13, params);
+
+    let mut computed_tag = vec![0u8; expected_tag.len()];
+    argon2
+        .hash_password_into(&password, &salt, &mut computed_tag)
+        .expect("argon2 hashing failed");
+
+    assert!(
+        constant_time_eq(&computed_tag, &expected_tag),
+        "password does not match the PHC hash"
+    );
+
+    env::commit_slice((salt, m_cost, t_cost, p_cost).abi_encode().as_slice());
+}
+
+fn constant_time_eq(a: &[u8], b: &[u8]) -> bool {
+    if a.len() != b.len() {
+        return false;
+    }
+    a.iter().zip(b.iter()).fold(0u8, |acc, (x, y)| acc | (x ^ y)) == 0
+}
@@ -0,0 +1,56 @@
+use alloy_primitives::{Bytes, B256};
+use alloy_sol_types::SolValue;
+use risc0_zkvm::guest::env;
+use sha2::{Digest, Sha256};
+
+const BLOCK_SIZE: usize = 64;
+
+fn hmac_sha256(key: &[u8], message: &[u8]) -> [u8; 32] {
+    // RFC 2104: derive a block-sized key, hashing it down first if it's too long.
+    let mut key_block = [0u8; BLOCK_SIZE];
+    if key.len() > BLOCK_SIZE {
+        let hashed = Sha256::digest(key);
+        key_block[..hashed.len()].copy_from_slice(&hashed);
+    } else {
+        key_block[..key.len()].copy_from_slice(key);
+    }
+
+    let mut ipad = [0u8; BLOCK_SIZE];
+    let mut opad = [0u8; BLOCK_SIZE];
+    for i in 0..BLOCK_SIZE {
+        ipad[i] = key_block[i] ^ 0x36;
+        opad[i] = key_block[i] ^ 0x5c;
+    }
+
+    let mut inner = ipad.to_vec();
+    inner.extend_from_slice(message);
+    let inner_digest = Sha256::digest(&inner);
+
+    let mut outer = opad.to_vec();
+    outer.extend_from_slice(&inner_digest);
+    Sha256::digest(&outer).into()
+}
+
+fn constant_time_eq(a: &[u8], b: &[u8]) -> bool {
+    if a.len() != b.len() {
+        return false;
+    }
+    a.iter().zip(b.iter()).fold(0u8, |acc, (x, y)| acc | (x ^ y)) == 0
+}
+
+fn main() {
+    // Private: the HMAC key.
+    let key: Vec<u8> = env::read();
+
+    // Public: the message and the tag it's claimed to authenticate.
+    let message: Vec<u8> = env::read();
+    let expected_tag: B256 = env::read();
+
+    let tag = hmac_sha256(&key, &message);
+    assert!(
+        constant_time_eq(&tag, expected_tag.as_slice()),
+        "HMAC tag does not match the message"
+    );
+
+    env::commit_slice((Bytes::from(message), expected_tag).abi_encode().as_slice());
+}
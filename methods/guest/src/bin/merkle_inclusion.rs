@@ -0,0 +1,40 @@
+use alloy_primitives::B256;
+use alloy_sol_types::SolValue;
+use risc0_zkvm::guest::env;
+use sha2::{Digest, Sha256};
+
+fn double_sha256(data: &[u8]) -> [u8; 32] {
+    let first = Sha256::digest(data);
+    Sha256::digest(first).into()
+}
+
+fn main() {
+    // Private: the leaf, its position, and the authentication path to the root.
+    let leaf: Vec<u8> = env::read();
+    let index: u64 = env::read();
+    let path: Vec<[u8; 32]> = env::read();
+
+    // Public: the Merkle root the leaf is claimed to belong to.
+    let root: B256 = env::read();
+
+    let mut current = double_sha256(&leaf);
+    for (level, sibling) in path.iter().enumerate() {
+        let mut combined = [0u8; 64];
+        if (index >> level) & 1 == 0 {
+            combined[..32].copy_from_slice(&current);
+            combined[32..].copy_from_slice(sibling);
+        } else {
+            combined[..32].copy_from_slice(sibling);
+            combined[32..].copy_from_slice(&current);
+        }
+        current = double_sha256(&combined);
+    }
+
+    assert_eq!(
+        B256::from(current),
+        root,
+        "authentication path does not lead to the expected root"
+    );
+
+    env::commit_slice((root, index).abi_encode().as_slice());
+}
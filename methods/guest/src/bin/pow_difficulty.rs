@@ -0,0 +1,39 @@
+use alloy_primitives::Bytes;
+use alloy_sol_types::SolValue;
+use risc0_zkvm::guest::env;
+use sha2::{Digest, Sha256};
+
+fn main() {
+    // Public: the challenge/namespace bytes and the minimum leading zero bits required.
+    let prefix: Vec<u8> = env::read();
+    let difficulty: u32 = env::read();
+    assert!(difficulty <= 256, "difficulty cannot exceed the digest size");
+
+    // Private: the nonce the prover found.
+    let nonce: Vec<u8> = env::read();
+
+    let mut preimage = prefix.clone();
+    preimage.extend_from_slice(&nonce);
+    let digest = Sha256::digest(&preimage);
+
+    let mut leading_zero_bits = 0u32;
+    for byte in digest.iter() {
+        if *byte == 0 {
+            leading_zero_bits += 8;
+            continue;
+        }
+        leading_zero_bits += byte.leading_zeros();
+        break;
+    }
+
+    assert!(
+        leading_zero_bits >= difficulty,
+        "digest does not meet the required difficulty"
+    );
+
+    env::commit_slice(
+        (Bytes::from(prefix), difficulty, Bytes::copy_from_slice(&digest))
+            .abi_encode()
+            .as_slice(),
+    );
+}
@@ -0,0 +1,18 @@
+use alloy_primitives::B256;
+use alloy_sol_types::SolValue;
+use risc0_zkvm::guest::env;
+use sha2::{Digest, Sha256};
+
+fn main() {
+    // Private: the preimage we're proving knowledge of.
+    let preimage: Vec<u8> = env::read();
+
+    // Public: the digest the preimage must hash to.
+    let expected_digest: B256 = env::read();
+
+    let digest = Sha256::digest(&preimage);
+    assert_eq!(digest.as_slice(), expected_digest.as_slice());
+
+    // Commit only the digest, never the preimage.
+    env::commit_slice(expected_digest.abi_encode().as_slice());
+}
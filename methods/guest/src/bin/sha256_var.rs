@@ -0,0 +1,19 @@
+use alloy_primitives::B256;
+use alloy_sol_types::SolValue;
+use risc0_zkvm::guest::env;
+use sha2::{Digest, Sha256};
+
+fn main() {
+    // Private: a fixed-capacity buffer and how many of its leading bytes are the real message.
+    // Only the digest and message_size are committed; the buffer itself stays out of the journal.
+    let buffer: Vec<u8> = env::read();
+    let message_size: u32 = env::read();
+    assert!(
+        message_size as usize <= buffer.len(),
+        "message_size exceeds the buffer capacity"
+    );
+
+    let digest = Sha256::digest(&buffer[..message_size as usize]);
+
+    env::commit_slice((B256::from_slice(&digest), message_size).abi_encode().as_slice());
+}